@@ -1,6 +1,7 @@
 use ethers::{
     types::{
-        transaction::eip2718::TypedTransaction, NameOrAddress, TransactionRequest, H160, U256,
+        transaction::eip2718::TypedTransaction, Eip1559TransactionRequest,
+        Eip2930TransactionRequest, NameOrAddress, TransactionRequest, H160, U256,
     },
     utils::rlp::{Decodable, Rlp},
 };
@@ -11,7 +12,7 @@ use near_sdk::{
     near_bindgen, require,
     serde::{Deserialize, Serialize},
     store::{UnorderedMap, UnorderedSet},
-    AccountId, BorshStorageKey, PanicOnDefault, Promise, PromiseError,
+    AccountId, BorshStorageKey, PanicOnDefault, Promise, PromiseError, PublicKey,
 };
 use near_sdk_contract_tools::{event, owner::*, standard::nep297::Event, Owner};
 
@@ -19,7 +20,7 @@ mod oracle;
 use oracle::{ext_oracle, AssetOptionalPrice, PriceData};
 
 mod signer_contract;
-use signer_contract::{ext_signer, MpcSignature};
+use signer_contract::{derive_evm_address, ext_signer, MpcSignature, PAYMASTER_KEY_PATH};
 
 mod signature_request;
 use signature_request::{SignatureRequest, SignatureRequestStatus};
@@ -69,6 +70,18 @@ pub struct Flags {
     is_receiver_whitelist_enabled: bool,
 }
 
+/// Per-EVM-chain configuration needed to price and relay a transaction on
+/// that chain: which oracle asset represents its native gas token, how many
+/// decimals that token uses, and the markup applied on top of the oracle
+/// price.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ChainConfig {
+    pub oracle_xchain_asset_id: String,
+    pub decimals: u8,
+    pub price_scale: (u128, u128),
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GasTokenPrice {
     pub local_per_xchain: (u128, u128),
@@ -85,8 +98,9 @@ pub struct TransactionInitiation {
 pub enum StorageKey {
     SenderWhitelist,
     ReceiverWhitelist,
-    SupportedForeignChainIds,
+    ChainConfigs,
     PendingTransactions,
+    PaymasterNonces,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, PanicOnDefault, Debug, Owner)]
@@ -96,13 +110,16 @@ pub struct Contract {
     pub signer_contract_id: AccountId,
     pub oracle_id: AccountId,
     pub oracle_local_asset_id: String,
-    pub oracle_xchain_asset_id: String,
-    pub supported_foreign_chain_ids: UnorderedSet<u64>,
+    pub chain_configs: UnorderedMap<u64, ChainConfig>,
     pub sender_whitelist: UnorderedSet<XChainAddress>,
     pub receiver_whitelist: UnorderedSet<XChainAddress>,
     pub flags: Flags,
-    pub price_scale: (u128, u128),
     pub pending_transactions: UnorderedMap<u64, Vec<SignatureRequest>>,
+    pub paymaster_address: Option<XChainAddress>,
+    /// Nonce counters keyed by `(chain_id, paymaster_address)` rather than
+    /// by chain id alone, so a paymaster key rotation starts fresh instead
+    /// of reusing a stale nonce against a new address.
+    pub paymaster_nonces: UnorderedMap<(u64, XChainAddress), u64>,
 }
 
 fn transaction_fee(
@@ -125,20 +142,19 @@ impl Contract {
         signer_contract_id: AccountId,
         oracle_id: AccountId,
         oracle_local_asset_id: String,
-        oracle_xchain_asset_id: String,
     ) -> Self {
         let mut contract = Self {
             next_unique_id: 0,
             signer_contract_id,
             oracle_id,
             oracle_local_asset_id,
-            oracle_xchain_asset_id,
-            supported_foreign_chain_ids: UnorderedSet::new(StorageKey::SupportedForeignChainIds), // TODO: Implement
+            chain_configs: UnorderedMap::new(StorageKey::ChainConfigs),
             sender_whitelist: UnorderedSet::new(StorageKey::SenderWhitelist),
             receiver_whitelist: UnorderedSet::new(StorageKey::ReceiverWhitelist),
             flags: Flags::default(),
-            price_scale: (120, 100), // +20% on top of market price
             pending_transactions: UnorderedMap::new(StorageKey::PendingTransactions),
+            paymaster_address: None,
+            paymaster_nonces: UnorderedMap::new(StorageKey::PaymasterNonces),
         };
 
         Owner::init(&mut contract, &env::predecessor_account_id());
@@ -211,14 +227,99 @@ impl Contract {
         self.sender_whitelist.clear();
     }
 
-    fn fetch_oracle(&mut self) -> Promise {
+    pub fn get_chain_config(&self, chain_id: U64) -> Option<&ChainConfig> {
+        self.chain_configs.get(&chain_id.0)
+    }
+
+    pub fn set_chain_config(&mut self, chain_id: U64, config: ChainConfig) {
+        self.assert_owner();
+        self.chain_configs.insert(chain_id.0, config);
+    }
+
+    pub fn remove_chain_config(&mut self, chain_id: U64) {
+        self.assert_owner();
+        self.chain_configs.remove(&chain_id.0);
+    }
+
+    fn chain_config(&self, chain_id: u64) -> &ChainConfig {
+        self.chain_configs
+            .get(&chain_id)
+            .unwrap_or_else(|| env::panic_str(&format!("Unsupported chain id {chain_id}")))
+    }
+
+    pub fn get_paymaster_address(&self) -> Option<&XChainAddress> {
+        self.paymaster_address.as_ref()
+    }
+
+    /// The next nonce to be used by the *current* paymaster address on a
+    /// chain. Returns `0` if no paymaster key has been derived yet, since no
+    /// paymaster transaction could have used that address before.
+    pub fn get_paymaster_nonce(&self, chain_id: U64) -> U64 {
+        let Some(paymaster_address) = self.paymaster_address else {
+            return 0.into();
+        };
+        self.paymaster_nonces
+            .get(&(chain_id.0, paymaster_address))
+            .copied()
+            .unwrap_or(0)
+            .into()
+    }
+
+    /// Resyncs the current paymaster address's nonce on a chain, in case it
+    /// drifts from the real chain state (e.g. a relayed paymaster
+    /// transaction failed to land).
+    pub fn set_paymaster_nonce(&mut self, chain_id: U64, nonce: U64) {
+        self.assert_owner();
+        let paymaster_address = self
+            .paymaster_address
+            .unwrap_or_else(|| env::panic_str("Paymaster key has not been derived yet"));
+        self.paymaster_nonces
+            .insert((chain_id.0, paymaster_address), nonce.0);
+    }
+
+    fn next_paymaster_nonce(&mut self, chain_id: u64, paymaster_address: XChainAddress) -> u64 {
+        let key = (chain_id, paymaster_address);
+        let nonce = self.paymaster_nonces.get(&key).copied().unwrap_or(0);
+        self.paymaster_nonces.insert(key, nonce + 1);
+        nonce
+    }
+
+    /// Derives and caches the paymaster's EVM address from the signer
+    /// contract's key for [`PAYMASTER_KEY_PATH`]. Must be called once
+    /// (and again after any signer key rotation) before the paymaster can
+    /// fund transactions.
+    pub fn derive_paymaster_key(&mut self) -> Promise {
+        self.assert_owner();
+        ext_signer::ext(self.signer_contract_id.clone())
+            .derived_public_key(PAYMASTER_KEY_PATH.to_string(), Some(env::current_account_id()))
+            .then(Self::ext(env::current_account_id()).derive_paymaster_key_callback())
+    }
+
+    #[private]
+    pub fn derive_paymaster_key_callback(
+        &mut self,
+        #[callback_result] result: Result<PublicKey, PromiseError>,
+    ) -> XChainAddress {
+        let public_key =
+            result.unwrap_or_else(|_| env::panic_str("Failed to derive paymaster key"));
+        let address = XChainAddress::from(derive_evm_address(&public_key));
+        self.paymaster_address = Some(address);
+        address
+    }
+
+    fn fetch_oracle(&mut self, xchain_asset_id: &str) -> Promise {
         ext_oracle::ext(self.oracle_id.clone()).get_price_data(Some(vec![
             self.oracle_local_asset_id.clone(),
-            self.oracle_xchain_asset_id.clone(),
+            xchain_asset_id.to_string(),
         ]))
     }
 
-    fn process_oracle_result(&self, result: Result<PriceData, PromiseError>) -> GasTokenPrice {
+    fn process_oracle_result(
+        &self,
+        xchain_asset_id: &str,
+        expected_xchain_decimals: u8,
+        result: Result<PriceData, PromiseError>,
+    ) -> GasTokenPrice {
         let price_data = result.unwrap_or_else(|_| env::panic_str("Failed to fetch price data"));
 
         let (local_price, xchain_price) = match &price_data.prices[..] {
@@ -229,13 +330,20 @@ impl Contract {
                 asset_id: second_asset_id,
                 price: Some(second_price),
             }] if first_asset_id == &self.oracle_local_asset_id
-                && second_asset_id == &self.oracle_xchain_asset_id =>
+                && second_asset_id == xchain_asset_id =>
             {
                 (first_price, second_price)
             }
             _ => env::panic_str("Invalid price data"),
         };
 
+        // Guard against the chain's configured token decimals silently
+        // drifting from what the oracle reports for the same asset.
+        require!(
+            xchain_price.decimals == expected_xchain_decimals,
+            "Oracle-reported decimals do not match the chain's configured decimals",
+        );
+
         GasTokenPrice {
             local_per_xchain: (
                 xchain_price.multiplier.0 * u128::from(local_price.decimals),
@@ -249,15 +357,38 @@ impl Contract {
 
     fn validate_transaction(&self, transaction: &TypedTransaction) {
         require!(
-            transaction.gas().is_some() && transaction.gas_price().is_some(),
+            transaction.gas().is_some() && effective_max_gas_price(transaction).is_some(),
             "Gas must be explicitly specified",
         );
 
+        let chain_id = transaction
+            .chain_id()
+            .unwrap_or_else(|| env::panic_str("Chain ID must be explicitly specified"))
+            .as_u64();
+
         require!(
-            transaction.chain_id().is_some(),
-            "Chain ID must be explicitly specified",
+            self.chain_configs.contains_key(&chain_id),
+            "Unsupported chain id",
         );
 
+        // Check access-list intrinsic gas and (if enabled) whitelist every
+        // address the transaction may touch via its access list.
+        if let Some(access_list) = access_list(transaction) {
+            require!(
+                *transaction.gas().unwrap() >= min_gas_for_access_list(access_list),
+                "Gas limit is insufficient to cover the access list intrinsic cost",
+            );
+
+            if self.flags.is_receiver_whitelist_enabled {
+                for item in &access_list.0 {
+                    require!(
+                        self.receiver_whitelist.contains(&item.address.into()),
+                        "Access list address is not whitelisted",
+                    );
+                }
+            }
+        }
+
         // Validate receiver
         let receiver: Option<XChainAddress> = match transaction.to() {
             Some(NameOrAddress::Name(_)) => {
@@ -302,15 +433,20 @@ impl Contract {
         &mut self,
         transaction_json: Option<TypedTransaction>,
         transaction_rlp: Option<String>,
+        transaction_rlp_signed: Option<String>,
     ) -> Promise {
         let deposit = env::attached_deposit();
         require!(deposit > 0, "Deposit is required to pay for gas");
 
-        let transaction = extract_transaction(transaction_json, transaction_rlp);
+        let transaction =
+            extract_transaction(transaction_json, transaction_rlp, transaction_rlp_signed);
 
         self.validate_transaction(&transaction);
 
-        self.fetch_oracle().then(
+        let chain_id = transaction.chain_id().unwrap().as_u64();
+        let oracle_xchain_asset_id = self.chain_config(chain_id).oracle_xchain_asset_id.clone();
+
+        self.fetch_oracle(&oracle_xchain_asset_id).then(
             Self::ext(env::current_account_id()).initiate_transaction_callback(
                 env::predecessor_account_id(),
                 deposit.into(),
@@ -327,11 +463,17 @@ impl Contract {
         transaction: TypedTransaction,
         #[callback_result] result: Result<PriceData, PromiseError>,
     ) -> TransactionInitiation {
-        let gas_token_price = self.process_oracle_result(result);
+        let chain_id = transaction.chain_id().unwrap().as_u64();
+        let chain_config = self.chain_config(chain_id).clone();
+        let gas_token_price = self.process_oracle_result(
+            &chain_config.oracle_xchain_asset_id,
+            chain_config.decimals,
+            result,
+        );
         let request_tokens_for_gas = tokens_for_gas(&transaction).unwrap(); // Validation ensures gas is set.
         let fee = transaction_fee(
             gas_token_price.local_per_xchain,
-            self.price_scale,
+            chain_config.price_scale,
             request_tokens_for_gas,
         );
         // TODO: Ensure that deposit is returned if any recoverable errors are encountered.
@@ -350,17 +492,51 @@ impl Contract {
             }
         }
 
-        let paymaster_transaction: TypedTransaction = TransactionRequest {
-            chain_id: Some(transaction.chain_id().unwrap()),
-            from: None, // TODO: PK gen
-            to: Some((*transaction.from().unwrap()).into()),
-            value: Some(request_tokens_for_gas),
-            ..Default::default()
-        }
-        .into();
+        let paymaster_address = self.paymaster_address.unwrap_or_else(|| {
+            env::panic_str(
+                "Paymaster key has not been derived yet; call `derive_paymaster_key` first",
+            )
+        });
+        let paymaster_nonce = self.next_paymaster_nonce(chain_id, paymaster_address);
+
+        let paymaster_transaction: TypedTransaction = match &transaction {
+            TypedTransaction::Eip1559(eip1559) => Eip1559TransactionRequest {
+                chain_id: Some(transaction.chain_id().unwrap()),
+                from: Some(paymaster_address.0),
+                to: Some((*transaction.from().unwrap()).into()),
+                value: Some(request_tokens_for_gas),
+                nonce: Some(paymaster_nonce.into()),
+                max_fee_per_gas: eip1559.max_fee_per_gas,
+                max_priority_fee_per_gas: eip1559.max_priority_fee_per_gas,
+                access_list: eip1559.access_list.clone(),
+                ..Default::default()
+            }
+            .into(),
+            TypedTransaction::Eip2930(eip2930) => Eip2930TransactionRequest {
+                tx: TransactionRequest {
+                    chain_id: Some(transaction.chain_id().unwrap()),
+                    from: Some(paymaster_address.0),
+                    to: Some((*transaction.from().unwrap()).into()),
+                    value: Some(request_tokens_for_gas),
+                    nonce: Some(paymaster_nonce.into()),
+                    ..Default::default()
+                },
+                access_list: eip2930.access_list.clone(),
+            }
+            .into(),
+            TypedTransaction::Legacy(_) => TransactionRequest {
+                chain_id: Some(transaction.chain_id().unwrap()),
+                from: Some(paymaster_address.0),
+                to: Some((*transaction.from().unwrap()).into()),
+                value: Some(request_tokens_for_gas),
+                nonce: Some(paymaster_nonce.into()),
+                ..Default::default()
+            }
+            .into(),
+        };
 
         let transactions = vec![
-            SignatureRequest::new("$", paymaster_transaction),
+            SignatureRequest::new(PAYMASTER_KEY_PATH, paymaster_transaction),
             SignatureRequest::new(env::predecessor_account_id(), transaction),
         ];
 
@@ -449,7 +625,12 @@ impl Contract {
 fn extract_transaction(
     transaction_json: Option<TypedTransaction>,
     transaction_rlp: Option<String>,
+    transaction_rlp_signed: Option<String>,
 ) -> TypedTransaction {
+    if let Some(rlp_hex) = transaction_rlp_signed {
+        return extract_signed_transaction(rlp_hex);
+    }
+
     transaction_json
         .or_else(|| {
             transaction_rlp.map(|rlp_hex| {
@@ -463,7 +644,363 @@ fn extract_transaction(
         })
         .unwrap_or_else(|| {
             env::panic_str(
-                "A transaction must be provided in `transaction_json` or `transaction_rlp`",
+                "A transaction must be provided in `transaction_json`, `transaction_rlp`, or \
+                 `transaction_rlp_signed`",
             )
         })
 }
+
+/// Decodes an already-signed transaction, recovers the signer's address from
+/// the transaction sighash and ECDSA signature, and sets it as the
+/// transaction's authoritative `from` so downstream whitelist checks cannot
+/// be bypassed by a spoofed `from` field.
+///
+/// Note there is no separate "declared chain id" to cross-check the
+/// signature against here: for legacy transactions, `TypedTransaction::
+/// decode_signed` derives `chain_id()` from the signature's own `v` per
+/// EIP-155, so the two can never disagree.
+fn extract_signed_transaction(transaction_rlp_signed: String) -> TypedTransaction {
+    let rlp_bytes = hex::decode(transaction_rlp_signed)
+        .unwrap_or_else(|_| env::panic_str("Error decoding `transaction_rlp_signed` as hex"));
+    let rlp = Rlp::new(&rlp_bytes);
+    let (mut transaction, signature) = TypedTransaction::decode_signed(&rlp).unwrap_or_else(|_| {
+        env::panic_str("Error decoding `transaction_rlp_signed` as a signed transaction")
+    });
+
+    let sender = signature
+        .recover(transaction.sighash())
+        .unwrap_or_else(|_| env::panic_str("Failed to recover transaction sender"));
+
+    transaction.set_from(sender);
+
+    transaction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::{Eip1559TransactionRequest, Eip2930TransactionRequest};
+    use near_sdk::{json_types::U128, test_utils::accounts, test_utils::VMContextBuilder, testing_env};
+    use oracle::Price;
+
+    fn new_contract() -> Contract {
+        set_context(accounts(3), 0);
+        let mut contract = Contract::new(accounts(1), accounts(2), "local.token".to_string());
+        contract.set_chain_config(
+            U64(1),
+            ChainConfig {
+                oracle_xchain_asset_id: "xchain.token".to_string(),
+                decimals: 18,
+                price_scale: (120, 100),
+            },
+        );
+        contract
+    }
+
+    fn set_context(predecessor: AccountId, deposit: u128) {
+        let mut builder = VMContextBuilder::new();
+        builder.predecessor_account_id(predecessor);
+        builder.attached_deposit(deposit.into());
+        testing_env!(builder.build());
+    }
+
+    fn base_request() -> TransactionRequest {
+        TransactionRequest::new()
+            .to(H160::repeat_byte(0xaa))
+            .from(H160::repeat_byte(0xbb))
+            .gas(21_000)
+            .chain_id(1u64)
+    }
+
+    #[test]
+    fn transaction_fee_rounds_up() {
+        // 1001 * 120 / 100 = 1201.2, rounded up to 1202.
+        let fee = transaction_fee((1, 1), (120, 100), U256::from(1001));
+        assert_eq!(fee, 1202);
+    }
+
+    #[test]
+    fn initiate_transaction_legacy() {
+        let mut contract = new_contract();
+        set_context(accounts(0), 1);
+        let transaction: TypedTransaction = base_request().gas_price(1).into();
+        contract.initiate_transaction(Some(transaction), None, None);
+    }
+
+    #[test]
+    fn initiate_transaction_eip2930() {
+        let mut contract = new_contract();
+        set_context(accounts(0), 1);
+        let transaction: TypedTransaction = Eip2930TransactionRequest {
+            tx: base_request().gas_price(1),
+            access_list: Default::default(),
+        }
+        .into();
+        contract.initiate_transaction(Some(transaction), None, None);
+    }
+
+    #[test]
+    fn initiate_transaction_eip1559() {
+        let mut contract = new_contract();
+        set_context(accounts(0), 1);
+        let transaction: TypedTransaction = Eip1559TransactionRequest::new()
+            .to(H160::repeat_byte(0xaa))
+            .from(H160::repeat_byte(0xbb))
+            .gas(21_000)
+            .chain_id(1u64)
+            .max_fee_per_gas(1)
+            .max_priority_fee_per_gas(1)
+            .into();
+        contract.initiate_transaction(Some(transaction), None, None);
+    }
+
+    /// A price data result under which one xchain gas unit is worth
+    /// 1/18 of a local token, matching the `decimals: 18` configured by
+    /// `new_contract` for chain id 1.
+    fn price_data_result() -> Result<PriceData, PromiseError> {
+        Ok(PriceData {
+            timestamp: U64(0),
+            recency_duration_sec: 0,
+            prices: vec![
+                AssetOptionalPrice {
+                    asset_id: "local.token".to_string(),
+                    price: Some(Price {
+                        multiplier: U128(1),
+                        decimals: 1,
+                    }),
+                },
+                AssetOptionalPrice {
+                    asset_id: "xchain.token".to_string(),
+                    price: Some(Price {
+                        multiplier: U128(1),
+                        decimals: 18,
+                    }),
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn initiate_transaction_callback_legacy() {
+        set_context(accounts(0), 1400);
+        let mut contract = new_contract();
+        let paymaster_address = XChainAddress::from(H160::repeat_byte(0xee));
+        contract.paymaster_address = Some(paymaster_address);
+
+        let transaction: TypedTransaction = base_request().gas_price(1).into();
+        let initiation = contract.initiate_transaction_callback(
+            accounts(0),
+            U128(1400),
+            transaction,
+            price_data_result(),
+        );
+
+        let pending = contract
+            .pending_transactions
+            .get(&initiation.id.0)
+            .unwrap();
+        let paymaster_request = &pending[0];
+        match &paymaster_request.transaction.0 {
+            TypedTransaction::Legacy(tx) => {
+                assert_eq!(tx.from, Some(paymaster_address.0));
+                assert_eq!(tx.to, Some(H160::repeat_byte(0xbb).into()));
+                assert_eq!(tx.nonce, Some(0.into()));
+                assert_eq!(tx.value, Some(21_000.into()));
+            }
+            other => panic!("expected a legacy paymaster transaction, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn initiate_transaction_callback_eip2930() {
+        set_context(accounts(0), 1560);
+        let mut contract = new_contract();
+        let paymaster_address = XChainAddress::from(H160::repeat_byte(0xee));
+        contract.paymaster_address = Some(paymaster_address);
+
+        let access_list: ethers::types::transaction::eip2930::AccessList =
+            vec![ethers::types::transaction::eip2930::AccessListItem {
+                address: H160::repeat_byte(0xcc),
+                storage_keys: vec![],
+            }]
+            .into();
+        let transaction: TypedTransaction = Eip2930TransactionRequest {
+            tx: base_request().gas_price(1).gas(23_400),
+            access_list: access_list.clone(),
+        }
+        .into();
+        let initiation = contract.initiate_transaction_callback(
+            accounts(0),
+            U128(1560),
+            transaction,
+            price_data_result(),
+        );
+
+        let pending = contract
+            .pending_transactions
+            .get(&initiation.id.0)
+            .unwrap();
+        let paymaster_request = &pending[0];
+        match &paymaster_request.transaction.0 {
+            TypedTransaction::Eip2930(tx) => {
+                assert_eq!(tx.tx.from, Some(paymaster_address.0));
+                assert_eq!(tx.tx.to, Some(H160::repeat_byte(0xbb).into()));
+                assert_eq!(tx.tx.nonce, Some(0.into()));
+                // The paymaster's funding transaction is built with a copy of
+                // the original transaction's access list.
+                assert_eq!(tx.access_list, access_list);
+            }
+            other => panic!("expected an EIP-2930 paymaster transaction, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn initiate_transaction_callback_eip1559() {
+        set_context(accounts(0), 1400);
+        let mut contract = new_contract();
+        let paymaster_address = XChainAddress::from(H160::repeat_byte(0xee));
+        contract.paymaster_address = Some(paymaster_address);
+
+        let transaction: TypedTransaction = Eip1559TransactionRequest::new()
+            .to(H160::repeat_byte(0xaa))
+            .from(H160::repeat_byte(0xbb))
+            .gas(21_000)
+            .chain_id(1u64)
+            .max_fee_per_gas(1)
+            .max_priority_fee_per_gas(1)
+            .into();
+        let initiation = contract.initiate_transaction_callback(
+            accounts(0),
+            U128(1400),
+            transaction,
+            price_data_result(),
+        );
+
+        let pending = contract
+            .pending_transactions
+            .get(&initiation.id.0)
+            .unwrap();
+        let paymaster_request = &pending[0];
+        match &paymaster_request.transaction.0 {
+            TypedTransaction::Eip1559(tx) => {
+                assert_eq!(tx.from, Some(paymaster_address.0));
+                assert_eq!(tx.to, Some(H160::repeat_byte(0xbb).into()));
+                assert_eq!(tx.nonce, Some(0.into()));
+                assert_eq!(tx.max_fee_per_gas, Some(1.into()));
+                assert_eq!(tx.max_priority_fee_per_gas, Some(1.into()));
+            }
+            other => panic!("expected an EIP-1559 paymaster transaction, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Gas limit is insufficient")]
+    fn initiate_transaction_rejects_underfunded_access_list_gas() {
+        let mut contract = new_contract();
+        set_context(accounts(0), 1);
+        let transaction: TypedTransaction = Eip2930TransactionRequest {
+            tx: base_request().gas_price(1),
+            access_list: vec![ethers::types::transaction::eip2930::AccessListItem {
+                address: H160::repeat_byte(0xcc),
+                storage_keys: vec![Default::default()],
+            }]
+            .into(),
+        }
+        .into();
+        contract.initiate_transaction(Some(transaction), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Access list address is not whitelisted")]
+    fn initiate_transaction_checks_access_list_against_receiver_whitelist() {
+        let mut contract = new_contract(); // predecessor is still the owner here
+        contract.add_to_receiver_whitelist(vec![H160::repeat_byte(0xaa).into()]);
+        contract.flags.is_receiver_whitelist_enabled = true;
+
+        set_context(accounts(0), 1);
+        let transaction: TypedTransaction = Eip2930TransactionRequest {
+            tx: base_request().gas_price(1).gas(23_400),
+            access_list: vec![ethers::types::transaction::eip2930::AccessListItem {
+                address: H160::repeat_byte(0xcc),
+                storage_keys: vec![],
+            }]
+            .into(),
+        }
+        .into();
+        contract.initiate_transaction(Some(transaction), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error decoding `transaction_rlp_signed`")]
+    fn initiate_transaction_rejects_invalid_signed_rlp() {
+        let mut contract = new_contract();
+        set_context(accounts(0), 1);
+        contract.initiate_transaction(None, None, Some("not-hex".to_string()));
+    }
+
+    #[test]
+    fn extract_signed_transaction_recovers_known_sender() {
+        // Private key `1`; its EVM address is the same well-known test
+        // vector used in `signer_contract::tests`.
+        let mut key_bytes = [0u8; 32];
+        key_bytes[31] = 1;
+        let signing_key =
+            ethers::core::k256::ecdsa::SigningKey::from_bytes(key_bytes.as_slice().into())
+                .unwrap();
+        let expected_sender =
+            H160::from_slice(&hex::decode("7e5f4552091a69125d5dfcb7b8c2659029395bdf").unwrap());
+
+        let chain_id = 1u64;
+        let transaction: TypedTransaction = base_request().gas_price(1).chain_id(chain_id).into();
+
+        let (sig, recovery_id) = signing_key
+            .sign_prehash_recoverable(transaction.sighash().as_bytes())
+            .unwrap();
+        let signature = ethers::types::Signature {
+            r: U256::from_big_endian(&sig.r().to_bytes()),
+            s: U256::from_big_endian(&sig.s().to_bytes()),
+            v: chain_id * 2 + 35 + u64::from(recovery_id.to_byte()),
+        };
+        let rlp_signed = hex::encode(transaction.rlp_signed(&signature));
+
+        let recovered = extract_signed_transaction(rlp_signed);
+        assert_eq!(recovered.from(), Some(&expected_sender));
+    }
+
+    #[test]
+    fn paymaster_nonce_increments_per_chain() {
+        let mut contract = new_contract();
+        let paymaster_address = XChainAddress::from(H160::repeat_byte(0xee));
+        contract.paymaster_address = Some(paymaster_address);
+
+        assert_eq!(contract.get_paymaster_nonce(U64(1)), U64(0));
+        assert_eq!(contract.next_paymaster_nonce(1, paymaster_address), 0);
+        assert_eq!(contract.next_paymaster_nonce(1, paymaster_address), 1);
+        assert_eq!(contract.get_paymaster_nonce(U64(1)), U64(2));
+        // A different chain id has its own, independent counter.
+        assert_eq!(contract.next_paymaster_nonce(2, paymaster_address), 0);
+    }
+
+    #[test]
+    fn paymaster_nonce_resets_after_key_rotation() {
+        let mut contract = new_contract();
+        let old_address = XChainAddress::from(H160::repeat_byte(0xee));
+        contract.paymaster_address = Some(old_address);
+        contract.next_paymaster_nonce(1, old_address);
+        assert_eq!(contract.get_paymaster_nonce(U64(1)), U64(1));
+
+        // Rotating to a new paymaster address starts that address's nonce
+        // fresh, instead of inheriting the old address's count.
+        let new_address = XChainAddress::from(H160::repeat_byte(0xff));
+        contract.paymaster_address = Some(new_address);
+        assert_eq!(contract.get_paymaster_nonce(U64(1)), U64(0));
+    }
+
+    #[test]
+    fn set_paymaster_nonce_resyncs() {
+        let mut contract = new_contract(); // predecessor is still the owner here
+        contract.paymaster_address = Some(XChainAddress::from(H160::repeat_byte(0xee)));
+        contract.set_paymaster_nonce(U64(1), U64(42));
+        assert_eq!(contract.get_paymaster_nonce(U64(1)), U64(42));
+    }
+}
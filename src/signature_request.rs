@@ -0,0 +1,81 @@
+use ethers::{
+    types::{transaction::eip2718::TypedTransaction, Signature},
+    utils::rlp::{Decodable, Rlp},
+};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+/// Newtype wrapper making [`TypedTransaction`] borsh-(de)serializable by
+/// round-tripping it through RLP, since ethers does not implement borsh.
+#[derive(Clone, Debug)]
+pub struct TransactionWrapper(pub TypedTransaction);
+
+impl BorshSerialize for TransactionWrapper {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0.rlp().to_vec(), writer)
+    }
+}
+
+impl BorshDeserialize for TransactionWrapper {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let bytes: Vec<u8> = BorshDeserialize::deserialize_reader(reader)?;
+        let rlp = Rlp::new(&bytes);
+        let transaction = TypedTransaction::decode(&rlp).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid transaction RLP")
+        })?;
+        Ok(Self(transaction))
+    }
+}
+
+/// Newtype wrapper making [`Signature`] borsh-(de)serializable.
+#[derive(Clone, Debug)]
+pub struct SignatureWrapper(pub Signature);
+
+impl BorshSerialize for SignatureWrapper {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0.to_vec(), writer)
+    }
+}
+
+impl BorshDeserialize for SignatureWrapper {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let bytes: Vec<u8> = BorshDeserialize::deserialize_reader(reader)?;
+        let signature = Signature::try_from(bytes.as_slice()).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid signature bytes")
+        })?;
+        Ok(Self(signature))
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub enum SignatureRequestStatus {
+    Pending { in_flight: bool, key_path: String },
+    Signed { signature: SignatureWrapper },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct SignatureRequest {
+    pub transaction: TransactionWrapper,
+    pub status: SignatureRequestStatus,
+}
+
+impl SignatureRequest {
+    pub fn new(key_path: impl ToString, transaction: TypedTransaction) -> Self {
+        Self {
+            transaction: TransactionWrapper(transaction),
+            status: SignatureRequestStatus::Pending {
+                in_flight: false,
+                key_path: key_path.to_string(),
+            },
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self.status, SignatureRequestStatus::Pending { .. })
+    }
+
+    pub fn set_signature(&mut self, signature: Signature) {
+        self.status = SignatureRequestStatus::Signed {
+            signature: SignatureWrapper(signature),
+        };
+    }
+}
@@ -0,0 +1,95 @@
+use ethers::{
+    types::{H160, Signature},
+    utils::keccak256,
+};
+use near_sdk::{ext_contract, require, AccountId, PublicKey};
+
+/// The key-derivation path used for the paymaster's relayer account. Shared
+/// between signing the paymaster's funding transaction and deriving its EVM
+/// address, since both must refer to the same underlying key.
+pub const PAYMASTER_KEY_PATH: &str = "$";
+
+/// Derives the EVM address corresponding to a secp256k1 public key returned
+/// by the MPC signer contract, the same way an Ethereum address is derived
+/// from any secp256k1 public key: keccak256 of the uncompressed point, last
+/// 20 bytes.
+pub fn derive_evm_address(public_key: &PublicKey) -> H160 {
+    // `as_bytes()` returns the raw `[curve_tag || key]` bytes with no
+    // length prefix, unlike `try_to_vec()`'s borsh (length-prefixed)
+    // encoding of the underlying `Vec<u8>`.
+    let bytes = public_key.as_bytes();
+
+    require!(bytes.len() == 65, "Expected a secp256k1 public key");
+
+    let hash = keccak256(&bytes[1..]);
+    H160::from_slice(&hash[12..])
+}
+
+/// Mirrors the signature response shape returned by the MPC signer
+/// contract: an affine point `big_r`, a scalar `s`, and a recovery id.
+#[derive(near_sdk::serde::Serialize, near_sdk::serde::Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MpcSignature {
+    pub big_r: String,
+    pub s: String,
+    pub recovery_id: u8,
+}
+
+impl TryFrom<MpcSignature> for Signature {
+    type Error = String;
+
+    fn try_from(value: MpcSignature) -> Result<Self, Self::Error> {
+        let r = value
+            .big_r
+            .strip_prefix("0x")
+            .unwrap_or(&value.big_r)
+            .parse()
+            .map_err(|_| "Invalid `big_r` in MPC signature")?;
+        let s = value
+            .s
+            .strip_prefix("0x")
+            .unwrap_or(&value.s)
+            .parse()
+            .map_err(|_| "Invalid `s` in MPC signature")?;
+
+        Ok(Signature {
+            r,
+            s,
+            v: u64::from(value.recovery_id) + 27,
+        })
+    }
+}
+
+#[ext_contract(ext_signer)]
+pub trait SignerInterface {
+    fn sign(&mut self, payload: [u8; 32], path: String) -> MpcSignature;
+    fn derived_public_key(&self, path: String, predecessor: Option<AccountId>) -> PublicKey;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::CurveType;
+
+    #[test]
+    fn derive_evm_address_matches_known_vector() {
+        // The secp256k1 generator point G, i.e. the public key for private
+        // key `1`. Its EVM address is a well-known test vector:
+        // 0x7e5f4552091a69125d5dfcb7b8c2659029395bdf.
+        let mut key_bytes = hex::decode(
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        )
+        .unwrap();
+        key_bytes.extend(
+            hex::decode("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8")
+                .unwrap(),
+        );
+
+        let public_key = PublicKey::from_parts(CurveType::SECP256K1, key_bytes).unwrap();
+
+        assert_eq!(
+            derive_evm_address(&public_key),
+            H160::from_slice(&hex::decode("7e5f4552091a69125d5dfcb7b8c2659029395bdf").unwrap()),
+        );
+    }
+}
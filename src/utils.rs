@@ -0,0 +1,123 @@
+use ethers::types::{
+    transaction::{eip2718::TypedTransaction, eip2930::AccessList},
+    U256,
+};
+
+/// Base intrinsic gas cost of any transaction, per the yellow paper.
+const BASE_TX_GAS: u64 = 21_000;
+/// Per EIP-2930: additional intrinsic gas for each address in the access list.
+const ACCESS_LIST_ADDRESS_GAS: u64 = 2_400;
+/// Per EIP-2930: additional intrinsic gas for each storage key in the access list.
+const ACCESS_LIST_STORAGE_KEY_GAS: u64 = 1_900;
+
+/// Returns the access list carried by a type-1/type-2 transaction, or `None`
+/// for legacy transactions, which have no such concept.
+pub fn access_list(transaction: &TypedTransaction) -> Option<&AccessList> {
+    match transaction {
+        TypedTransaction::Legacy(_) => None,
+        TypedTransaction::Eip2930(tx) => Some(&tx.access_list),
+        TypedTransaction::Eip1559(tx) => Some(&tx.access_list),
+    }
+}
+
+/// The minimum gas limit required to cover a transaction's EIP-2930 intrinsic
+/// access-list cost: 21000 base gas, plus 2400 per listed address, plus 1900
+/// per listed storage key.
+pub fn min_gas_for_access_list(access_list: &AccessList) -> U256 {
+    let num_addresses = access_list.0.len() as u64;
+    let num_keys: u64 = access_list
+        .0
+        .iter()
+        .map(|item| item.storage_keys.len() as u64)
+        .sum();
+
+    U256::from(
+        BASE_TX_GAS
+            + ACCESS_LIST_ADDRESS_GAS * num_addresses
+            + ACCESS_LIST_STORAGE_KEY_GAS * num_keys,
+    )
+}
+
+/// Returns the gas price a transaction is willing to pay at most: the
+/// `gas_price` for legacy/2930 transactions, or `max_fee_per_gas` for
+/// EIP-1559 (type-2) transactions, which carry no `gas_price` field.
+pub fn effective_max_gas_price(transaction: &TypedTransaction) -> Option<U256> {
+    match transaction {
+        TypedTransaction::Legacy(tx) => tx.gas_price,
+        TypedTransaction::Eip2930(tx) => tx.tx.gas_price,
+        TypedTransaction::Eip1559(tx) => tx.max_fee_per_gas,
+    }
+}
+
+/// Upper-bounds the amount of xchain gas token the relayer might need to
+/// reimburse for a given transaction, based on its gas limit and worst-case
+/// price.
+///
+/// Returns `None` if the transaction does not carry enough fee information
+/// to compute a bound (callers are expected to have validated this already).
+pub fn tokens_for_gas(transaction: &TypedTransaction) -> Option<U256> {
+    let gas = transaction.gas()?;
+    let price = effective_max_gas_price(transaction)?;
+    Some(gas * price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::{
+        transaction::eip2930::AccessListItem, Eip1559TransactionRequest, Eip2930TransactionRequest,
+        TransactionRequest, H160, H256,
+    };
+
+    #[test]
+    fn tokens_for_gas_legacy() {
+        let tx: TypedTransaction = TransactionRequest::new()
+            .gas(21_000)
+            .gas_price(100)
+            .into();
+        assert_eq!(tokens_for_gas(&tx), Some(U256::from(21_000 * 100)));
+    }
+
+    #[test]
+    fn tokens_for_gas_eip2930() {
+        let tx: TypedTransaction = Eip2930TransactionRequest {
+            tx: TransactionRequest::new().gas(21_000).gas_price(100),
+            access_list: Default::default(),
+        }
+        .into();
+        assert_eq!(tokens_for_gas(&tx), Some(U256::from(21_000 * 100)));
+    }
+
+    #[test]
+    fn tokens_for_gas_eip1559() {
+        let tx: TypedTransaction = Eip1559TransactionRequest::new()
+            .gas(21_000)
+            .max_fee_per_gas(100)
+            .into();
+        assert_eq!(tokens_for_gas(&tx), Some(U256::from(21_000 * 100)));
+    }
+
+    #[test]
+    fn tokens_for_gas_missing_price_is_none() {
+        let tx: TypedTransaction = TransactionRequest::new().gas(21_000).into();
+        assert_eq!(tokens_for_gas(&tx), None);
+    }
+
+    #[test]
+    fn access_list_is_none_for_legacy() {
+        let tx: TypedTransaction = TransactionRequest::new().into();
+        assert!(access_list(&tx).is_none());
+    }
+
+    #[test]
+    fn min_gas_for_access_list_accounts_for_addresses_and_keys() {
+        let list = AccessList(vec![AccessListItem {
+            address: H160::repeat_byte(1),
+            storage_keys: vec![H256::repeat_byte(2), H256::repeat_byte(3)],
+        }]);
+        assert_eq!(
+            min_gas_for_access_list(&list),
+            U256::from(21_000 + 2_400 + 1_900 * 2)
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use ethers::types::H160;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Deserializer, Serialize, Serializer},
+};
+use std::str::FromStr;
+
+/// A foreign-chain (EVM) address, stored and compared in its raw 20-byte
+/// form but serialized to/from JSON as a `0x`-prefixed hex string.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct XChainAddress(pub H160);
+
+impl From<H160> for XChainAddress {
+    fn from(address: H160) -> Self {
+        Self(address)
+    }
+}
+
+impl From<&H160> for XChainAddress {
+    fn from(address: &H160) -> Self {
+        Self(*address)
+    }
+}
+
+impl From<XChainAddress> for H160 {
+    fn from(address: XChainAddress) -> Self {
+        address.0
+    }
+}
+
+impl Serialize for XChainAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:?}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for XChainAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        H160::from_str(&s)
+            .map(Self)
+            .map_err(near_sdk::serde::de::Error::custom)
+    }
+}